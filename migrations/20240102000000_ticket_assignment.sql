@@ -0,0 +1,34 @@
+-- Agent availability for automatic ticket assignment
+ALTER TABLE users ADD COLUMN available BOOLEAN NOT NULL DEFAULT true;
+
+-- Agent skills, matched against ticket tags by the skills strategy
+CREATE TABLE agent_skills (
+    agent_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    skill VARCHAR(100) NOT NULL,
+    PRIMARY KEY (agent_id, skill)
+);
+
+-- Tags table
+CREATE TABLE tags (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(100) UNIQUE NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
+);
+
+-- Ticket tags join table
+CREATE TABLE ticket_tags (
+    ticket_id INTEGER NOT NULL REFERENCES tickets(id) ON DELETE CASCADE,
+    tag_id INTEGER NOT NULL REFERENCES tags(id) ON DELETE CASCADE,
+    PRIMARY KEY (ticket_id, tag_id)
+);
+
+-- Round-robin cursor shared by every server instance
+CREATE TABLE assignment_state (
+    id BOOLEAN PRIMARY KEY DEFAULT true CHECK (id),
+    last_agent_id INTEGER REFERENCES users(id) ON DELETE SET NULL
+);
+
+INSERT INTO assignment_state (id) VALUES (true);
+
+CREATE INDEX idx_agent_skills_skill ON agent_skills(skill);
+CREATE INDEX idx_ticket_tags_tag_id ON ticket_tags(tag_id);
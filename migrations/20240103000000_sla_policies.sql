@@ -0,0 +1,52 @@
+-- Tickets waiting on the customer pause their SLA clock
+ALTER TYPE ticket_status ADD VALUE IF NOT EXISTS 'pending' AFTER 'in_progress';
+ALTER TYPE notification_type ADD VALUE IF NOT EXISTS 'sla_breached';
+
+-- Business hours calendars used to compute SLA deadlines
+CREATE TABLE business_calendars (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(100) UNIQUE NOT NULL,
+    utc_offset_minutes INTEGER NOT NULL DEFAULT 0,
+    working_days INTEGER[] NOT NULL DEFAULT '{1,2,3,4,5}',
+    day_start TIME NOT NULL DEFAULT '09:00',
+    day_end TIME NOT NULL DEFAULT '17:00',
+    holidays DATE[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    CHECK (day_end > day_start)
+);
+
+-- SLA policies, one per ticket priority. A NULL calendar means 24/7.
+CREATE TABLE sla_policies (
+    id SERIAL PRIMARY KEY,
+    priority ticket_priority UNIQUE NOT NULL,
+    first_response_minutes INTEGER NOT NULL CHECK (first_response_minutes > 0),
+    resolution_minutes INTEGER NOT NULL CHECK (resolution_minutes > 0),
+    calendar_id INTEGER REFERENCES business_calendars(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
+);
+
+-- SLA tracking on tickets
+ALTER TABLE tickets
+    ADD COLUMN sla_policy_id INTEGER REFERENCES sla_policies(id) ON DELETE SET NULL,
+    ADD COLUMN first_response_due_at TIMESTAMP WITH TIME ZONE,
+    ADD COLUMN resolution_due_at TIMESTAMP WITH TIME ZONE,
+    ADD COLUMN first_responded_at TIMESTAMP WITH TIME ZONE,
+    ADD COLUMN sla_paused_at TIMESTAMP WITH TIME ZONE,
+    ADD COLUMN sla_paused_minutes INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN first_response_breached_at TIMESTAMP WITH TIME ZONE,
+    ADD COLUMN resolution_breached_at TIMESTAMP WITH TIME ZONE;
+
+CREATE INDEX idx_tickets_first_response_due_at ON tickets(first_response_due_at) WHERE first_response_breached_at IS NULL;
+CREATE INDEX idx_tickets_resolution_due_at ON tickets(resolution_due_at) WHERE resolution_breached_at IS NULL;
+
+CREATE TRIGGER update_business_calendars_updated_at BEFORE UPDATE ON business_calendars FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+CREATE TRIGGER update_sla_policies_updated_at BEFORE UPDATE ON sla_policies FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+-- Default policies (24/7 until a calendar is attached)
+INSERT INTO sla_policies (priority, first_response_minutes, resolution_minutes) VALUES
+('critical', 60, 240),
+('high', 240, 1440),
+('medium', 480, 2880),
+('low', 1440, 5760);
@@ -0,0 +1,40 @@
+ALTER TYPE notification_type ADD VALUE IF NOT EXISTS 'ticket_escalated';
+
+-- Ticket history (audit trail of changes and automated actions)
+CREATE TABLE ticket_history (
+    id SERIAL PRIMARY KEY,
+    ticket_id INTEGER NOT NULL REFERENCES tickets(id) ON DELETE CASCADE,
+    actor_id INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    event_type VARCHAR(50) NOT NULL,
+    details JSONB NOT NULL DEFAULT '{}',
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
+);
+
+-- Escalation rules evaluated by the escalation worker.
+-- NULL conditions match every ticket; NULL actions are skipped.
+CREATE TABLE escalation_rules (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(255) NOT NULL,
+    enabled BOOLEAN NOT NULL DEFAULT true,
+    condition_priority ticket_priority,
+    no_agent_comment_minutes INTEGER CHECK (no_agent_comment_minutes > 0),
+    unassigned_minutes INTEGER CHECK (unassigned_minutes > 0),
+    raise_priority BOOLEAN NOT NULL DEFAULT false,
+    assign_agent_id INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    notify_admins BOOLEAN NOT NULL DEFAULT false,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
+);
+
+-- Each rule fires at most once per ticket
+CREATE TABLE escalation_rule_runs (
+    rule_id INTEGER NOT NULL REFERENCES escalation_rules(id) ON DELETE CASCADE,
+    ticket_id INTEGER NOT NULL REFERENCES tickets(id) ON DELETE CASCADE,
+    fired_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    PRIMARY KEY (rule_id, ticket_id)
+);
+
+CREATE INDEX idx_ticket_history_ticket_id ON ticket_history(ticket_id);
+CREATE INDEX idx_escalation_rule_runs_ticket_id ON escalation_rule_runs(ticket_id);
+
+CREATE TRIGGER update_escalation_rules_updated_at BEFORE UPDATE ON escalation_rules FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
@@ -0,0 +1,24 @@
+-- Automation rules run when tickets are created or updated.
+-- Empty conditions match every ticket; NULL actions are skipped.
+CREATE TABLE automation_rules (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(255) NOT NULL,
+    enabled BOOLEAN NOT NULL DEFAULT true,
+    trigger_event VARCHAR(20) NOT NULL DEFAULT 'created' CHECK (trigger_event IN ('created', 'updated', 'any')),
+    keywords TEXT[] NOT NULL DEFAULT '{}',
+    condition_priority ticket_priority,
+    customer_domain VARCHAR(255),
+    condition_tags TEXT[] NOT NULL DEFAULT '{}',
+    set_priority ticket_priority,
+    add_tag VARCHAR(100),
+    assign_agent_id INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    reply_content TEXT,
+    webhook_url TEXT,
+    created_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
+);
+
+CREATE INDEX idx_automation_rules_trigger_event ON automation_rules(trigger_event) WHERE enabled;
+
+CREATE TRIGGER update_automation_rules_updated_at BEFORE UPDATE ON automation_rules FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
@@ -0,0 +1,6 @@
+-- Public replies are visible to the customer; internal notes only to agents and admins
+CREATE TYPE comment_visibility AS ENUM ('public', 'internal');
+
+ALTER TABLE comments ADD COLUMN visibility comment_visibility NOT NULL DEFAULT 'public';
+
+CREATE INDEX idx_comments_visibility ON comments(visibility);
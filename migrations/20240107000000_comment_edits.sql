@@ -0,0 +1,4 @@
+-- Comment edits and soft deletes
+ALTER TABLE comments
+    ADD COLUMN edited_at TIMESTAMP WITH TIME ZONE,
+    ADD COLUMN deleted_at TIMESTAMP WITH TIME ZONE;
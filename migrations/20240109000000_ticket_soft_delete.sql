@@ -0,0 +1,4 @@
+-- Soft delete for tickets; rows are purged after the retention period
+ALTER TABLE tickets ADD COLUMN deleted_at TIMESTAMP WITH TIME ZONE;
+
+CREATE INDEX idx_tickets_deleted_at ON tickets(deleted_at) WHERE deleted_at IS NOT NULL;
@@ -0,0 +1,14 @@
+-- Tag names are stored normalized: trimmed, lower case, inner whitespace as '-'
+INSERT INTO tags (name)
+SELECT DISTINCT regexp_replace(lower(btrim(name)), '\s+', '-', 'g') FROM tags
+WHERE btrim(name) <> ''
+ON CONFLICT (name) DO NOTHING;
+
+INSERT INTO ticket_tags (ticket_id, tag_id)
+SELECT tt.ticket_id, normalized.id
+FROM ticket_tags tt
+JOIN tags original ON original.id = tt.tag_id
+JOIN tags normalized ON normalized.name = regexp_replace(lower(btrim(original.name)), '\s+', '-', 'g')
+ON CONFLICT DO NOTHING;
+
+DELETE FROM tags WHERE name <> regexp_replace(lower(btrim(name)), '\s+', '-', 'g') OR btrim(name) = '';
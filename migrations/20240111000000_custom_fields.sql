@@ -0,0 +1,23 @@
+-- Admin-defined ticket fields. Values live in tickets.custom_fields keyed by
+-- the field key. A required field with no categories applies to every ticket.
+CREATE TABLE custom_fields (
+    id SERIAL PRIMARY KEY,
+    key VARCHAR(100) UNIQUE NOT NULL CHECK (key ~ '^[a-z][a-z0-9_]*$'),
+    label VARCHAR(255) NOT NULL,
+    field_type VARCHAR(20) NOT NULL CHECK (field_type IN ('text', 'number', 'date', 'select', 'multi_select')),
+    options TEXT[] NOT NULL DEFAULT '{}',
+    required BOOLEAN NOT NULL DEFAULT false,
+    categories TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    CHECK (field_type NOT IN ('select', 'multi_select') OR cardinality(options) > 0)
+);
+
+ALTER TABLE tickets
+    ADD COLUMN category VARCHAR(100),
+    ADD COLUMN custom_fields JSONB NOT NULL DEFAULT '{}';
+
+CREATE INDEX idx_tickets_category ON tickets(category);
+CREATE INDEX idx_tickets_custom_fields ON tickets USING GIN (custom_fields jsonb_path_ops);
+
+CREATE TRIGGER update_custom_fields_updated_at BEFORE UPDATE ON custom_fields FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
@@ -0,0 +1,38 @@
+-- Ticket queues. Agents only work tickets in queues they belong to; tickets
+-- without a queue stay visible to every agent.
+CREATE TABLE queues (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(100) UNIQUE NOT NULL,
+    description TEXT,
+    default_priority ticket_priority,
+    -- NULL falls back to AUTO_ASSIGN_STRATEGY
+    assignment_strategy VARCHAR(20) CHECK (assignment_strategy IN ('disabled', 'round_robin', 'least_open', 'skills')),
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
+);
+
+CREATE TABLE queue_members (
+    queue_id INTEGER NOT NULL REFERENCES queues(id) ON DELETE CASCADE,
+    agent_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    PRIMARY KEY (queue_id, agent_id)
+);
+
+ALTER TABLE tickets ADD COLUMN queue_id INTEGER REFERENCES queues(id) ON DELETE SET NULL;
+
+-- Queues can override the global SLA policy for a priority
+ALTER TABLE sla_policies
+    DROP CONSTRAINT sla_policies_priority_key,
+    ADD COLUMN queue_id INTEGER REFERENCES queues(id) ON DELETE CASCADE;
+
+CREATE UNIQUE INDEX idx_sla_policies_global_priority ON sla_policies(priority) WHERE queue_id IS NULL;
+CREATE UNIQUE INDEX idx_sla_policies_queue_priority ON sla_policies(queue_id, priority) WHERE queue_id IS NOT NULL;
+
+CREATE INDEX idx_tickets_queue_id ON tickets(queue_id);
+CREATE INDEX idx_queue_members_agent_id ON queue_members(agent_id);
+
+CREATE TRIGGER update_queues_updated_at BEFORE UPDATE ON queues FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+INSERT INTO queues (name, description) VALUES
+('Billing', 'Invoices, payments and refunds'),
+('Technical', 'Product issues and bugs'),
+('Sales', 'Pricing and plan questions');
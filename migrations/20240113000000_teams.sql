@@ -0,0 +1,21 @@
+-- Agent teams. Tickets can be assigned to a team as well as to an agent.
+CREATE TABLE teams (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(100) UNIQUE NOT NULL,
+    lead_id INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
+);
+
+CREATE TABLE team_members (
+    team_id INTEGER NOT NULL REFERENCES teams(id) ON DELETE CASCADE,
+    agent_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    PRIMARY KEY (team_id, agent_id)
+);
+
+ALTER TABLE tickets ADD COLUMN assigned_team_id INTEGER REFERENCES teams(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_tickets_assigned_team_id ON tickets(assigned_team_id);
+CREATE INDEX idx_team_members_agent_id ON team_members(agent_id);
+
+CREATE TRIGGER update_teams_updated_at BEFORE UPDATE ON teams FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
@@ -0,0 +1,15 @@
+-- Ticket watchers: staff users following a ticket, or CC email addresses
+-- added by the customer. Exactly one of user_id and email is set.
+CREATE TABLE ticket_watchers (
+    id SERIAL PRIMARY KEY,
+    ticket_id INTEGER NOT NULL REFERENCES tickets(id) ON DELETE CASCADE,
+    user_id INTEGER REFERENCES users(id) ON DELETE CASCADE,
+    email VARCHAR(255),
+    added_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    CHECK ((user_id IS NULL) <> (email IS NULL)),
+    UNIQUE (ticket_id, user_id),
+    UNIQUE (ticket_id, email)
+);
+
+CREATE INDEX idx_ticket_watchers_user_id ON ticket_watchers(user_id);
@@ -0,0 +1,22 @@
+-- Duplicate tickets merged into another ticket. Each merge records what it
+-- moved so it can be undone for a limited time.
+ALTER TABLE tickets ADD COLUMN merged_into_id INTEGER REFERENCES tickets(id) ON DELETE SET NULL;
+
+CREATE TABLE ticket_merges (
+    id SERIAL PRIMARY KEY,
+    source_ticket_id INTEGER NOT NULL REFERENCES tickets(id) ON DELETE CASCADE,
+    target_ticket_id INTEGER NOT NULL REFERENCES tickets(id) ON DELETE CASCADE,
+    merged_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    source_status ticket_status NOT NULL,
+    source_resolved_at TIMESTAMP WITH TIME ZONE,
+    moved_comment_ids INTEGER[] NOT NULL DEFAULT '{}',
+    moved_attachment_ids INTEGER[] NOT NULL DEFAULT '{}',
+    moved_watcher_ids INTEGER[] NOT NULL DEFAULT '{}',
+    added_watcher_ids INTEGER[] NOT NULL DEFAULT '{}',
+    merge_comment_id INTEGER REFERENCES comments(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    undone_at TIMESTAMP WITH TIME ZONE,
+    CHECK (source_ticket_id <> target_ticket_id)
+);
+
+CREATE INDEX idx_ticket_merges_source_ticket_id ON ticket_merges(source_ticket_id);
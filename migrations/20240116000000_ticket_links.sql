@@ -0,0 +1,19 @@
+-- Links between tickets, stored from the source's point of view:
+--   parent:  source is the parent (problem) of target (incident)
+--   blocks:  source blocks target
+--   related: symmetric, stored once with source < target
+CREATE TABLE ticket_links (
+    id SERIAL PRIMARY KEY,
+    source_ticket_id INTEGER NOT NULL REFERENCES tickets(id) ON DELETE CASCADE,
+    target_ticket_id INTEGER NOT NULL REFERENCES tickets(id) ON DELETE CASCADE,
+    link_type VARCHAR(20) NOT NULL CHECK (link_type IN ('parent', 'blocks', 'related')),
+    created_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    CHECK (source_ticket_id <> target_ticket_id),
+    CHECK (link_type <> 'related' OR source_ticket_id < target_ticket_id),
+    UNIQUE (source_ticket_id, target_ticket_id, link_type)
+);
+
+-- A ticket has at most one parent
+CREATE UNIQUE INDEX idx_ticket_links_single_parent ON ticket_links(target_ticket_id) WHERE link_type = 'parent';
+CREATE INDEX idx_ticket_links_target_ticket_id ON ticket_links(target_ticket_id);
@@ -0,0 +1,20 @@
+-- Canned responses agents apply to tickets. Personal macros are only visible
+-- to their owner; shared ones to every agent. NULL actions are skipped.
+CREATE TABLE macros (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(255) NOT NULL,
+    content TEXT NOT NULL,
+    visibility comment_visibility NOT NULL DEFAULT 'public',
+    set_status ticket_status,
+    set_priority ticket_priority,
+    add_tags TEXT[] NOT NULL DEFAULT '{}',
+    shared BOOLEAN NOT NULL DEFAULT false,
+    owner_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
+);
+
+CREATE INDEX idx_macros_owner_id ON macros(owner_id);
+CREATE INDEX idx_macros_shared ON macros(shared) WHERE shared;
+
+CREATE TRIGGER update_macros_updated_at BEFORE UPDATE ON macros FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
@@ -0,0 +1,17 @@
+-- Satisfaction surveys sent to the customer when a ticket is resolved. The
+-- agent and queue are copied at send time so reports stay stable when the
+-- ticket is reassigned later.
+CREATE TABLE csat_surveys (
+    id SERIAL PRIMARY KEY,
+    ticket_id INTEGER NOT NULL REFERENCES tickets(id) ON DELETE CASCADE,
+    customer_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    agent_id INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    queue_id INTEGER REFERENCES queues(id) ON DELETE SET NULL,
+    rating SMALLINT CHECK (rating BETWEEN 1 AND 5),
+    comment TEXT,
+    sent_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    rated_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX idx_csat_surveys_ticket_id ON csat_surveys(ticket_id);
+CREATE INDEX idx_csat_surveys_rated_at ON csat_surveys(rated_at) WHERE rating IS NOT NULL;
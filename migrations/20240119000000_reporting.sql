@@ -0,0 +1,33 @@
+-- Reporting support: reopen counts, indexes for date-range reports and a
+-- daily volume view refreshed by the report worker
+
+ALTER TABLE tickets ADD COLUMN reopen_count INTEGER NOT NULL DEFAULT 0;
+
+CREATE OR REPLACE FUNCTION count_ticket_reopens()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF OLD.status IN ('resolved', 'closed') AND NEW.status NOT IN ('resolved', 'closed') THEN
+        NEW.reopen_count = OLD.reopen_count + 1;
+    END IF;
+    RETURN NEW;
+END;
+$$ language 'plpgsql';
+
+CREATE TRIGGER count_tickets_reopens BEFORE UPDATE OF status ON tickets FOR EACH ROW EXECUTE FUNCTION count_ticket_reopens();
+
+CREATE INDEX idx_tickets_resolved_at ON tickets(resolved_at) WHERE resolved_at IS NOT NULL;
+CREATE INDEX idx_comments_created_at ON comments(created_at);
+
+CREATE MATERIALIZED VIEW ticket_daily_volume AS
+SELECT day, SUM(created)::bigint as created, SUM(resolved)::bigint as resolved
+FROM (
+    SELECT (created_at AT TIME ZONE 'UTC')::date as day, 1 as created, 0 as resolved
+    FROM tickets WHERE deleted_at IS NULL
+    UNION ALL
+    SELECT (resolved_at AT TIME ZONE 'UTC')::date, 0, 1
+    FROM tickets WHERE deleted_at IS NULL AND resolved_at IS NOT NULL
+) volume
+GROUP BY day;
+
+-- Required for REFRESH MATERIALIZED VIEW CONCURRENTLY
+CREATE UNIQUE INDEX idx_ticket_daily_volume_day ON ticket_daily_volume(day);
@@ -0,0 +1,17 @@
+-- Background ticket exports. The filters are the resolved ticket list filters;
+-- the finished file is written to EXPORT_DIR.
+CREATE TABLE export_jobs (
+    id SERIAL PRIMARY KEY,
+    format VARCHAR(10) NOT NULL CHECK (format IN ('csv', 'ndjson')),
+    filters JSONB NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'running', 'completed', 'failed')),
+    file_name TEXT,
+    ticket_count INTEGER,
+    error TEXT,
+    requested_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    started_at TIMESTAMP WITH TIME ZONE,
+    completed_at TIMESTAMP WITH TIME ZONE
+);
+
+CREATE INDEX idx_export_jobs_pending ON export_jobs(id) WHERE status = 'pending';
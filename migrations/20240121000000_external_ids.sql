@@ -0,0 +1,5 @@
+-- Ids from the help desk records were imported from. Re-running an import
+-- skips rows whose external id already exists.
+ALTER TABLE users ADD COLUMN external_id VARCHAR(255) UNIQUE;
+ALTER TABLE tickets ADD COLUMN external_id VARCHAR(255) UNIQUE;
+ALTER TABLE comments ADD COLUMN external_id VARCHAR(255) UNIQUE;
@@ -0,0 +1,11 @@
+-- Single sign-on through an OpenID Connect provider. Users signed in through
+-- the provider are linked by its subject identifier.
+ALTER TABLE users ADD COLUMN oidc_subject VARCHAR(255) UNIQUE;
+
+-- Pending authorization requests, consumed by the callback
+CREATE TABLE oidc_login_states (
+    state VARCHAR(64) PRIMARY KEY,
+    nonce VARCHAR(64) NOT NULL,
+    code_verifier VARCHAR(128) NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,16 @@
+-- Business calendars can follow a named time zone (with daylight saving time)
+-- instead of a fixed UTC offset
+ALTER TABLE business_calendars ADD COLUMN timezone VARCHAR(64);
+
+-- A reopened ticket gets a fresh resolution breach check
+CREATE OR REPLACE FUNCTION clear_resolution_breach_on_reopen()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF OLD.status IN ('resolved', 'closed') AND NEW.status NOT IN ('resolved', 'closed') THEN
+        NEW.resolution_breached_at := NULL;
+    END IF;
+    RETURN NEW;
+END;
+$$ language 'plpgsql';
+
+CREATE TRIGGER clear_ticket_resolution_breach BEFORE UPDATE OF status ON tickets FOR EACH ROW EXECUTE FUNCTION clear_resolution_breach_on_reopen();
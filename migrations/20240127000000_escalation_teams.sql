@@ -0,0 +1,2 @@
+-- Escalation rules can hand tickets to a team (for example the on-call group)
+ALTER TABLE escalation_rules ADD COLUMN assign_team_id INTEGER REFERENCES teams(id) ON DELETE SET NULL;
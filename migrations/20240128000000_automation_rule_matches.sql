@@ -0,0 +1,11 @@
+-- Tickets a rule currently matches and has already acted on. A rule fires when
+-- a ticket starts matching and not again until the ticket stops matching, so
+-- later updates (including its own) don't trigger it over and over.
+CREATE TABLE automation_rule_matches (
+    rule_id INTEGER NOT NULL REFERENCES automation_rules(id) ON DELETE CASCADE,
+    ticket_id INTEGER NOT NULL REFERENCES tickets(id) ON DELETE CASCADE,
+    fired_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (rule_id, ticket_id)
+);
+
+CREATE INDEX idx_automation_rule_matches_ticket_id ON automation_rule_matches(ticket_id);
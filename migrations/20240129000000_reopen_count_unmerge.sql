@@ -0,0 +1,12 @@
+-- Undoing a merge restores the duplicate's previous status; that is not a
+-- reopen. The unmerge is the only update that clears merged_into_id.
+CREATE OR REPLACE FUNCTION count_ticket_reopens()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF OLD.status IN ('resolved', 'closed') AND NEW.status NOT IN ('resolved', 'closed')
+        AND NOT (OLD.merged_into_id IS NOT NULL AND NEW.merged_into_id IS NULL) THEN
+        NEW.reopen_count = OLD.reopen_count + 1;
+    END IF;
+    RETURN NEW;
+END;
+$$ language 'plpgsql';
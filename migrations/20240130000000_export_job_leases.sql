@@ -0,0 +1,6 @@
+-- Running exports hold a lease that the worker renews while it writes the
+-- file. A job whose lease ran out (its worker died) is picked up again by any
+-- instance instead of every instance requeueing all running jobs on startup.
+ALTER TABLE export_jobs ADD COLUMN lease_expires_at TIMESTAMP WITH TIME ZONE;
+
+CREATE INDEX idx_export_jobs_running ON export_jobs(lease_expires_at) WHERE status = 'running';